@@ -0,0 +1,126 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::Team;
+
+/// League-phase parameters declared once in `config.toml`, instead of being
+/// hardcoded into the solver.
+#[derive(Debug, Deserialize)]
+pub struct TournamentConfig {
+    pub pots: u8,
+    pub matches_per_team: u8,
+    pub country_cap: u8,
+}
+
+#[derive(Debug)]
+pub enum LoadError {
+    Io(String, std::io::Error),
+    Toml(String, toml::de::Error),
+    DuplicateTeam(String),
+    UnknownPot { team: String, pot: u8, pots: u8 },
+    UnevenPots(HashMap<u8, usize>),
+    EmptyPot(u8),
+    MatchesPerTeamMismatch { matches_per_team: u8, pots: u8 },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io(path, e) => write!(f, "failed to read {path}: {e}"),
+            LoadError::Toml(path, e) => write!(f, "failed to parse {path}: {e}"),
+            LoadError::DuplicateTeam(name) => write!(f, "duplicate team name: {name}"),
+            LoadError::UnknownPot { team, pot, pots } => write!(
+                f,
+                "{team} is assigned to pot {pot}, but config.toml only declares {pots} pots"
+            ),
+            LoadError::UnevenPots(counts) => write!(f, "pots are not the same size: {counts:?}"),
+            LoadError::EmptyPot(pot) => write!(f, "pot {pot} is declared in config.toml but has no teams"),
+            LoadError::MatchesPerTeamMismatch { matches_per_team, pots } => write!(
+                f,
+                "matches_per_team is {matches_per_team}, but {pots} pots only support {} (one home and one away fixture per pot)",
+                2 * u16::from(*pots)
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// Reads the top-level tournament config from `path`.
+pub fn load_config(path: &Path) -> Result<TournamentConfig, LoadError> {
+    let text = fs::read_to_string(path).map_err(|e| LoadError::Io(path.display().to_string(), e))?;
+    toml::from_str(&text).map_err(|e| LoadError::Toml(path.display().to_string(), e))
+}
+
+/// Reads every `*.toml` file in `dir`, each deserialized into a `Team`.
+pub fn load_teams(dir: &Path) -> Result<Vec<Team>, LoadError> {
+    let mut paths: Vec<_> = fs::read_dir(dir)
+        .map_err(|e| LoadError::Io(dir.display().to_string(), e))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .collect();
+    paths.sort();
+
+    let mut teams = Vec::with_capacity(paths.len());
+    for path in paths {
+        let text = fs::read_to_string(&path).map_err(|e| LoadError::Io(path.display().to_string(), e))?;
+        let team: Team = toml::from_str(&text).map_err(|e| LoadError::Toml(path.display().to_string(), e))?;
+        teams.push(team);
+    }
+
+    Ok(teams)
+}
+
+/// Checks `teams` against `config` and reports every problem found — unknown
+/// or empty pot numbers, duplicate team names, pots of unequal size, a
+/// `matches_per_team` that the pot count can't actually produce — instead of
+/// failing on the first one.
+pub fn validate(teams: &[Team], config: &TournamentConfig) -> Result<(), Vec<LoadError>> {
+    let mut errors = Vec::new();
+    let mut seen_names = HashSet::new();
+    let mut pot_sizes: HashMap<u8, usize> = HashMap::new();
+
+    for team in teams {
+        if !seen_names.insert(&team.name) {
+            errors.push(LoadError::DuplicateTeam(team.name.clone()));
+        }
+
+        if team.group == 0 || team.group > config.pots {
+            errors.push(LoadError::UnknownPot {
+                team: team.name.clone(),
+                pot: team.group,
+                pots: config.pots,
+            });
+        } else {
+            *pot_sizes.entry(team.group).or_insert(0) += 1;
+        }
+    }
+
+    for pot in 1..=config.pots {
+        if !pot_sizes.contains_key(&pot) {
+            errors.push(LoadError::EmptyPot(pot));
+        }
+    }
+
+    if pot_sizes.values().collect::<HashSet<_>>().len() > 1 {
+        errors.push(LoadError::UnevenPots(pot_sizes));
+    }
+
+    if config.matches_per_team != 2 * config.pots {
+        errors.push(LoadError::MatchesPerTeamMismatch {
+            matches_per_team: config.matches_per_team,
+            pots: config.pots,
+        });
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
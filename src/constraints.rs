@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use crate::Team;
+
+/// A read-only view of the solver's partial assignment, handed to
+/// constraints so they can inspect state beyond the pair they're asked
+/// about (pot tuples, country counts, ...) without borrowing `CSPMatches`
+/// itself.
+pub struct ScheduleState<'a> {
+    pub group_requirements: &'a HashMap<Team, HashMap<u8, (u8, u8)>>,
+    pub country_counts: &'a HashMap<Team, HashMap<String, u8>>,
+    /// Maximum clubs from one association a team may face, read from
+    /// `config.toml`.
+    pub country_cap: u8,
+}
+
+impl<'a> ScheduleState<'a> {
+    /// True while `team` still has an open home (or, with `home` false, away)
+    /// slot for `group`, i.e. that side of its `(home_count, away_count)`
+    /// tuple is still 0.
+    pub fn group_slot_free(&self, team: &Team, group: u8, home: bool) -> bool {
+        self.group_requirements
+            .get(team)
+            .and_then(|reqs| reqs.get(&group))
+            .is_none_or(|(h, a)| if home { *h < 1 } else { *a < 1 })
+    }
+
+    /// How many clubs from `country` `team` has already faced.
+    pub fn country_count(&self, team: &Team, country: &str) -> u8 {
+        self.country_counts
+            .get(team)
+            .and_then(|counts| counts.get(country))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    pub fn country_cap_ok(&self, team: &Team, opponent: &Team) -> bool {
+        self.country_count(team, &opponent.country) < self.country_cap
+    }
+}
+
+/// A rule the solver consults when deciding whether `team` (home) can be
+/// booked against `opponent` (away). `check` is a hard veto; `cost` instead
+/// lets a constraint express a soft preference — at each step the solver
+/// tries its cheapest legal candidates first, biasing the schedule it finds
+/// towards a low total soft cost. This is a greedy heuristic, not a true
+/// minimization: it does not backtrack to trade a step's cost for a lower
+/// total once a complete schedule is found. Implementing just `check` (the
+/// default `cost` is always zero) is enough for a hard constraint.
+pub trait Constraint {
+    fn check(&self, ctx: &ScheduleState, team: &Team, opponent: &Team) -> bool;
+
+    fn cost(&self, _ctx: &ScheduleState, _team: &Team, _opponent: &Team) -> i64 {
+        0
+    }
+}
+
+/// Hard: no two clubs from the same country may meet.
+pub struct DifferentCountryConstraint;
+
+impl Constraint for DifferentCountryConstraint {
+    fn check(&self, _ctx: &ScheduleState, team: &Team, opponent: &Team) -> bool {
+        team.country != opponent.country
+    }
+}
+
+/// Hard: a team may only take one home and one away fixture per pot.
+pub struct PotBalanceConstraint;
+
+impl Constraint for PotBalanceConstraint {
+    fn check(&self, ctx: &ScheduleState, team: &Team, opponent: &Team) -> bool {
+        ctx.group_slot_free(team, opponent.group, true) && ctx.group_slot_free(opponent, team.group, false)
+    }
+}
+
+/// Hard: no team may face more than `ScheduleState::country_cap` clubs from
+/// one association.
+pub struct CountryCapConstraint;
+
+impl Constraint for CountryCapConstraint {
+    fn check(&self, ctx: &ScheduleState, team: &Team, opponent: &Team) -> bool {
+        ctx.country_cap_ok(team, opponent) && ctx.country_cap_ok(opponent, team)
+    }
+}
+
+/// Soft: prefer opponents from associations a team hasn't already faced, so
+/// the draw tends toward variety even before the hard cap kicks in.
+pub struct PreferUnseenCountryConstraint;
+
+impl Constraint for PreferUnseenCountryConstraint {
+    fn check(&self, _ctx: &ScheduleState, _team: &Team, _opponent: &Team) -> bool {
+        true
+    }
+
+    fn cost(&self, ctx: &ScheduleState, team: &Team, opponent: &Team) -> i64 {
+        i64::from(ctx.country_count(team, &opponent.country))
+    }
+}
@@ -0,0 +1,204 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::{CSPMatches, Match, Team};
+
+/// Output formats the schedule can be written in.
+pub enum Format {
+    Csv,
+    Json,
+    Yaml,
+}
+
+#[derive(Serialize, Clone)]
+struct TeamFixture {
+    opponent: String,
+    country: String,
+    group: u8,
+    home: bool,
+    round: u8,
+    kickoff: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize)]
+struct TeamDump {
+    team: String,
+    country: String,
+    group: u8,
+    fixtures: Vec<TeamFixture>,
+}
+
+/// One pot's worth of a team's opponents, for the structured YAML draw.
+#[derive(Serialize)]
+struct PotBreakdown {
+    pot: u8,
+    opponents: Vec<TeamFixture>,
+}
+
+/// A team entry in the structured YAML draw: who they are and their
+/// opponents broken down by pot, rather than the flat fixture list the
+/// CSV/JSON exports use.
+#[derive(Serialize)]
+struct TeamSummary {
+    name: String,
+    country: String,
+    group: u8,
+    opponents_by_pot: Vec<PotBreakdown>,
+}
+
+#[derive(Serialize)]
+struct RoundFixtures {
+    round: u8,
+    fixtures: Vec<Match>,
+}
+
+/// The full draw as the YAML export presents it: the team list with each
+/// team's opponents grouped by pot, followed by the fixtures grouped by
+/// round.
+#[derive(Serialize)]
+struct Draw {
+    teams: Vec<TeamSummary>,
+    rounds: Vec<RoundFixtures>,
+}
+
+impl CSPMatches {
+    /// Writes the full schedule to `path` in the given `format`, sorted by
+    /// matchday then by home team name.
+    pub fn save(&self, path: &str, format: Format) -> io::Result<()> {
+        let mut matches = self.scheduled_matches.clone();
+        matches.sort_by(|a, b| {
+            a.round
+                .cmp(&b.round)
+                .then_with(|| a.home_team.name.cmp(&b.home_team.name))
+        });
+
+        match format {
+            Format::Csv => Self::save_csv(&matches, path),
+            Format::Json => Self::save_json(&matches, path),
+            Format::Yaml => self.save_yaml(&matches, path),
+        }
+    }
+
+    /// `team`'s opponents from `scheduled_matches`, each carrying whether
+    /// `team` was at home and which pot the opponent came from. Shared by
+    /// the per-team JSON dump and the YAML draw's per-team pot breakdown.
+    fn team_fixtures(&self, team: &Team) -> Vec<TeamFixture> {
+        self.scheduled_matches
+            .iter()
+            .filter_map(|m| {
+                if m.home_team == *team {
+                    Some(TeamFixture {
+                        opponent: m.away_team.name.clone(),
+                        country: m.away_team.country.clone(),
+                        group: m.away_team.group,
+                        home: true,
+                        round: m.round,
+                        kickoff: m.kickoff,
+                    })
+                } else if m.away_team == *team {
+                    Some(TeamFixture {
+                        opponent: m.home_team.name.clone(),
+                        country: m.home_team.country.clone(),
+                        group: m.home_team.group,
+                        home: false,
+                        round: m.round,
+                        kickoff: m.kickoff,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    fn save_csv(matches: &[Match], path: &str) -> io::Result<()> {
+        let mut wtr = csv::Writer::from_path(path)?;
+        wtr.write_record(["Round", "Home Team", "Away Team", "Kickoff"])?;
+        for m in matches {
+            let kickoff = m.kickoff.map(|dt| dt.to_rfc3339()).unwrap_or_default();
+            wtr.write_record([
+                &m.round.to_string(),
+                &m.home_team.name,
+                &m.away_team.name,
+                &kickoff,
+            ])?;
+        }
+        wtr.flush()
+    }
+
+    fn save_json(matches: &[Match], path: &str) -> io::Result<()> {
+        let body = serde_json::to_string_pretty(matches).map_err(io::Error::other)?;
+        fs::write(path, body)
+    }
+
+    /// Writes the structured draw: the team list with each team's opponents
+    /// broken down by pot, followed by the fixtures grouped by round — the
+    /// distinguishing structure YAML is meant to make easy to read and diff,
+    /// rather than the same flat fixture list the CSV/JSON exports use.
+    fn save_yaml(&self, matches: &[Match], path: &str) -> io::Result<()> {
+        let mut rounds: Vec<RoundFixtures> = Vec::new();
+        for m in matches {
+            match rounds.iter_mut().find(|r| r.round == m.round) {
+                Some(r) => r.fixtures.push(m.clone()),
+                None => rounds.push(RoundFixtures {
+                    round: m.round,
+                    fixtures: vec![m.clone()],
+                }),
+            }
+        }
+
+        let teams = self
+            .teams
+            .iter()
+            .map(|team| {
+                let mut by_pot: Vec<PotBreakdown> = Vec::new();
+                for fixture in self.team_fixtures(team) {
+                    match by_pot.iter_mut().find(|p| p.pot == fixture.group) {
+                        Some(p) => p.opponents.push(fixture),
+                        None => by_pot.push(PotBreakdown {
+                            pot: fixture.group,
+                            opponents: vec![fixture],
+                        }),
+                    }
+                }
+                by_pot.sort_by_key(|p| p.pot);
+
+                TeamSummary {
+                    name: team.name.clone(),
+                    country: team.country.clone(),
+                    group: team.group,
+                    opponents_by_pot: by_pot,
+                }
+            })
+            .collect();
+
+        let draw = Draw { teams, rounds };
+        let body = serde_yaml::to_string(&draw).map_err(io::Error::other)?;
+        fs::write(path, body)
+    }
+
+    /// Writes one JSON file per team into `dir`, each listing that team's
+    /// opponents (home/away, pot, matchday) — a "static API" dump that
+    /// downstream tools can fetch without running the solver themselves.
+    pub fn export_team_dumps(&self, dir: &str) -> io::Result<()> {
+        fs::create_dir_all(dir)?;
+
+        for team in &self.teams {
+            let dump = TeamDump {
+                team: team.name.clone(),
+                country: team.country.clone(),
+                group: team.group,
+                fixtures: self.team_fixtures(team),
+            };
+
+            let body = serde_json::to_string_pretty(&dump).map_err(io::Error::other)?;
+            fs::write(Path::new(dir).join(format!("{}.json", team.name)), body)?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,48 +1,78 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::Path;
 
-#[derive(Clone, Hash, Eq, PartialEq, Debug)]
-struct Team {
-    name: String,
-    country: String,
-    group: u8,
-}
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
 
-impl Team {
-    fn new(name: String, country: String, group: u8) -> Team {
-        Team { name, country, group }
-    }
+mod constraints;
+use constraints::{
+    Constraint, CountryCapConstraint, DifferentCountryConstraint, PotBalanceConstraint,
+    PreferUnseenCountryConstraint, ScheduleState,
+};
+
+mod export;
+use export::Format;
+
+mod config;
+use config::TournamentConfig;
+
+#[derive(Clone, Hash, Eq, PartialEq, Debug, Serialize, Deserialize)]
+struct Team {
+    pub(crate) name: String,
+    pub(crate) country: String,
+    pub(crate) group: u8,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 struct Match {
-    home_team: Team,
-    away_team: Team,
+    pub(crate) home_team: Team,
+    pub(crate) away_team: Team,
+    pub(crate) round: u8,
+    pub(crate) kickoff: Option<DateTime<Utc>>,
 }
 
 impl Match {
     fn new(home_team: Team, away_team: Team) -> Match {
-        Match { home_team, away_team }
+        Match {
+            home_team,
+            away_team,
+            round: 0,
+            kickoff: None,
+        }
     }
 }
 
-fn constraint_different_country(team1: &Team, team2: &Team) -> bool {
-    team1.country != team2.country
+/// A single reversible change applied while the solver explores a branch.
+/// Backtracking replays these in reverse to restore exactly the prior state.
+#[derive(Debug)]
+enum Change {
+    DomainRemoved { owner: Team, value: Team },
+    GroupTracked { team: Team, group: u8, home: bool },
+    CountryTracked { team: Team, country: String },
+    MatchPushed,
 }
 
 struct CSPMatches {
-    teams: Vec<Team>,  
-    domains: HashMap<Team, HashSet<Team>>, 
-    constraints: Vec<fn(&Team, &Team) -> bool>,  
-    scheduled_matches: Vec<Match>,  
-    group_requirements: HashMap<Team, HashMap<u8, (u8, u8)>>, 
+    pub(crate) teams: Vec<Team>,
+    domains: HashMap<Team, HashSet<Team>>,
+    constraints: Vec<Box<dyn Constraint>>,
+    pub(crate) scheduled_matches: Vec<Match>,
+    group_requirements: HashMap<Team, HashMap<u8, (u8, u8)>>,
+    country_counts: HashMap<Team, HashMap<String, u8>>,
+    matches_per_team: u8,
+    country_cap: u8,
+    trail: Vec<Change>,
 }
 
 impl CSPMatches {
-    fn new(teams: Vec<Team>) -> CSPMatches {
+    fn new(teams: Vec<Team>, config: &TournamentConfig) -> CSPMatches {
         let domains = CSPMatches::initialize_domains(&teams);
-        let group_requirements = CSPMatches::initialize_group_requirements(&teams);
-        let constraints: Vec<fn(&Team, &Team) -> bool> = vec![
-            constraint_different_country, 
+        let group_requirements = CSPMatches::initialize_group_requirements(&teams, config.pots);
+        let constraints: Vec<Box<dyn Constraint>> = vec![
+            Box::new(DifferentCountryConstraint),
+            Box::new(PotBalanceConstraint),
+            Box::new(CountryCapConstraint),
+            Box::new(PreferUnseenCountryConstraint),
         ];
         CSPMatches {
             teams,
@@ -50,6 +80,10 @@ impl CSPMatches {
             constraints,
             scheduled_matches: Vec::new(),
             group_requirements,
+            country_counts: HashMap::new(),
+            matches_per_team: config.matches_per_team,
+            country_cap: config.country_cap,
+            trail: Vec::new(),
         }
     }
 
@@ -69,11 +103,11 @@ impl CSPMatches {
         domains
     }
 
-    fn initialize_group_requirements(teams: &[Team]) -> HashMap<Team, HashMap<u8, (u8, u8)>> {
+    fn initialize_group_requirements(teams: &[Team], pots: u8) -> HashMap<Team, HashMap<u8, (u8, u8)>> {
         let mut requirements = HashMap::new();
         for team in teams {
             let mut group_map = HashMap::new();
-            for group in 1..=4 {
+            for group in 1..=pots {
                 group_map.insert(group, (0, 0));
             }
             requirements.insert(team.clone(), group_map);
@@ -81,108 +115,534 @@ impl CSPMatches {
         requirements
     }
 
+    /// Borrows a read-only view of the partial assignment for constraints
+    /// that need more context than the pair they're being asked about.
+    fn state(&self) -> ScheduleState<'_> {
+        ScheduleState {
+            group_requirements: &self.group_requirements,
+            country_counts: &self.country_counts,
+            country_cap: self.country_cap,
+        }
+    }
+
+    /// True only if every registered hard constraint allows booking `team1`
+    /// at home against `team2`. This is a preview only — it mutates nothing.
     fn satisfies_constraints(&self, team1: &Team, team2: &Team) -> bool {
-        for constraint in &self.constraints {
-            if !(constraint)(team1, team2) {
-                return false;
+        let ctx = self.state();
+        self.constraints.iter().all(|c| c.check(&ctx, team1, team2))
+    }
+
+    /// Total soft-constraint cost of booking `team1` at home against
+    /// `team2`, used to order otherwise-legal candidates cheapest first.
+    fn soft_cost(&self, team1: &Team, team2: &Team) -> i64 {
+        let ctx = self.state();
+        self.constraints.iter().map(|c| c.cost(&ctx, team1, team2)).sum()
+    }
+
+    /// True while `team` still has an open home (or, with `home` false, away)
+    /// slot for `group`, i.e. that side of its `(home_count, away_count)`
+    /// tuple is still 0.
+    fn group_slot_free(&self, team: &Team, group: u8, home: bool) -> bool {
+        self.state().group_slot_free(team, group, home)
+    }
+
+    /// True once every pot tuple for `team` is within `(1, 1)` — the
+    /// invariant `update_group_tracking` enforces on every assignment.
+    fn is_pot_balanced(&self, team: &Team) -> bool {
+        self.group_requirements
+            .get(team)
+            .is_none_or(|reqs| reqs.values().all(|(h, a)| *h <= 1 && *a <= 1))
+    }
+
+    fn matches_played(&self, team: &Team) -> u8 {
+        self.group_requirements
+            .get(team)
+            .map_or(0, |reqs| reqs.values().map(|(h, a)| h + a).sum())
+    }
+
+    fn needs_more_matches(&self, team: &Team) -> bool {
+        self.matches_played(team) < self.matches_per_team
+    }
+
+    /// Records a fixture between `team1` and `team2` in the pot tracker, with
+    /// `team1` at home when `home` is true and away otherwise, refusing
+    /// — without mutating anything — if either side's group tuple
+    /// would be pushed past `(1, 1)`.
+    fn update_group_tracking(&mut self, team1: &Team, team2: &Team, home: bool) -> bool {
+        if !self.group_slot_free(team1, team2.group, home) || !self.group_slot_free(team2, team1.group, !home) {
+            return false;
+        }
+
+        let entry = self
+            .group_requirements
+            .get_mut(team1)
+            .unwrap()
+            .get_mut(&team2.group)
+            .unwrap();
+        if home {
+            entry.0 += 1;
+        } else {
+            entry.1 += 1;
+        }
+
+        let entry = self
+            .group_requirements
+            .get_mut(team2)
+            .unwrap()
+            .get_mut(&team1.group)
+            .unwrap();
+        if home {
+            entry.1 += 1;
+        } else {
+            entry.0 += 1;
+        }
+
+        true
+    }
+
+    fn increment_country(&mut self, team: &Team, country: &str) {
+        let counts = self.country_counts.entry(team.clone()).or_default();
+        *counts.entry(country.to_string()).or_insert(0) += 1;
+        self.trail.push(Change::CountryTracked {
+            team: team.clone(),
+            country: country.to_string(),
+        });
+    }
+
+    /// Removes `value` from `owner`'s domain, recording the removal on the
+    /// trail so a later backtrack can put it back.
+    fn remove_from_domain(&mut self, owner: &Team, value: &Team) {
+        if let Some(domain) = self.domains.get_mut(owner) {
+            if domain.remove(value) {
+                self.trail.push(Change::DomainRemoved {
+                    owner: owner.clone(),
+                    value: value.clone(),
+                });
             }
         }
+    }
+
+    /// Books `team` (home) vs `opponent` (away) as a tentative assignment,
+    /// pushing every side effect onto the trail so `undo_to` can unwind it.
+    /// Returns false (with nothing committed) if `update_group_tracking`
+    /// refuses the pairing, e.g. because a caller bypassed
+    /// `satisfies_constraints`.
+    fn assign(&mut self, team: &Team, opponent: &Team) -> bool {
+        if !self.update_group_tracking(team, opponent, true) {
+            return false;
+        }
+        self.trail.push(Change::GroupTracked {
+            team: team.clone(),
+            group: opponent.group,
+            home: true,
+        });
+        self.trail.push(Change::GroupTracked {
+            team: opponent.clone(),
+            group: team.group,
+            home: false,
+        });
 
+        self.scheduled_matches.push(Match::new(team.clone(), opponent.clone()));
+        self.trail.push(Change::MatchPushed);
+
+        self.increment_country(team, &opponent.country);
+        self.increment_country(opponent, &team.country);
+
+        self.remove_from_domain(team, opponent);
+        self.remove_from_domain(opponent, team);
+
+        self.prune_infeasible(team);
+        self.prune_infeasible(opponent);
+
+        debug_assert!(self.is_pot_balanced(team) && self.is_pot_balanced(opponent));
         true
     }
 
-    fn update_group_tracking(&mut self, team1: &Team, team2: &Team, home: bool) {
-        if let Some(reqs) = self.group_requirements.get_mut(team1) {
-            let entry = reqs.get_mut(&team2.group).unwrap();
-            if home {
-                entry.0 += 1;
-            } else {
-                entry.1 += 1;
+    /// Drops every remaining candidate from `team`'s domain that neither
+    /// orientation can legally book any more, now that this assignment has
+    /// moved one of `team`'s pot tuples or country counts. AC-3's `revise`
+    /// only checks that two domains still reciprocate each other — it knows
+    /// nothing about pot balance or the country cap — so without this, a
+    /// candidate that's already provably dead (e.g. `team`'s home slot for
+    /// that pot is full and the away slot is too) lingers in the domain
+    /// until the solver walks all the way down to it and rejects it one
+    /// candidate at a time, on every branch that reaches that point.
+    fn prune_infeasible(&mut self, team: &Team) {
+        let doomed: Vec<Team> = match self.domains.get(team) {
+            Some(domain) => domain
+                .iter()
+                .filter(|opponent| {
+                    !self.satisfies_constraints(team, opponent) && !self.satisfies_constraints(opponent, team)
+                })
+                .cloned()
+                .collect(),
+            None => return,
+        };
+
+        for opponent in doomed {
+            self.remove_from_domain(team, &opponent);
+            self.remove_from_domain(&opponent, team);
+        }
+    }
+
+    /// Unwinds every change recorded on the trail since `mark`, restoring
+    /// domains, pairings, group counters and scheduled matches.
+    fn undo_to(&mut self, mark: usize) {
+        while self.trail.len() > mark {
+            match self.trail.pop().unwrap() {
+                Change::DomainRemoved { owner, value } => {
+                    self.domains.get_mut(&owner).unwrap().insert(value);
+                }
+                Change::GroupTracked { team, group, home } => {
+                    let entry = self
+                        .group_requirements
+                        .get_mut(&team)
+                        .unwrap()
+                        .get_mut(&group)
+                        .unwrap();
+                    if home {
+                        entry.0 -= 1;
+                    } else {
+                        entry.1 -= 1;
+                    }
+                }
+                Change::CountryTracked { team, country } => {
+                    if let Some(count) = self
+                        .country_counts
+                        .get_mut(&team)
+                        .and_then(|counts| counts.get_mut(&country))
+                    {
+                        *count -= 1;
+                    }
+                }
+                Change::MatchPushed => {
+                    self.scheduled_matches.pop();
+                }
             }
         }
+    }
+
+    /// Minimum-Remaining-Values: the team still short of its `matches_per_team`
+    /// matches whose count of domain opponents that themselves still need
+    /// matches is smallest. Counting raw domain size would let a team's
+    /// "candidates" that have already played all their matches (and so can never actually
+    /// be booked) make its domain look bigger than it really is, steering
+    /// MRV away from the teams that are truly close to dead-ending.
+    fn select_unassigned_team(&self) -> Option<Team> {
+        self.teams
+            .iter()
+            .filter(|team| self.needs_more_matches(team))
+            .min_by_key(|team| {
+                self.domains.get(*team).map_or(0, |domain| {
+                    domain.iter().filter(|opponent| self.needs_more_matches(opponent)).count()
+                })
+            })
+            .cloned()
+    }
+
+    /// Candidate opponent `v` for `ti` is only consistent with `tj`'s domain
+    /// if, whenever `v` actually is `tj`, `tj`'s domain still offers `ti`
+    /// back — booking one side of a fixture without the other isn't legal.
+    fn binary_consistent(ti: &Team, v: &Team, tj: &Team, w: &Team) -> bool {
+        if v == tj && w != ti {
+            return false;
+        }
+        true
+    }
+
+    /// Removes every value from `domain[ti]` that has no supporting value
+    /// left in `domain[tj]`. Returns whether `domain[ti]` shrank. Borrows
+    /// both domains read-only to build the removal list instead of cloning
+    /// them, so the cost is proportional to what's actually removed rather
+    /// than to the full domain size on every arc.
+    fn revise(&mut self, ti: &Team, tj: &Team) -> bool {
+        let to_remove: Vec<Team> = match (self.domains.get(ti), self.domains.get(tj)) {
+            (Some(ti_domain), Some(tj_domain)) => ti_domain
+                .iter()
+                .filter(|v| !tj_domain.iter().any(|w| Self::binary_consistent(ti, v, tj, w)))
+                .cloned()
+                .collect(),
+            _ => return false,
+        };
 
-        if let Some(reqs) = self.group_requirements.get_mut(team2) {
-            let entry = reqs.get_mut(&team1.group).unwrap();
-            if home {
-                entry.1 += 1; 
+        let revised = !to_remove.is_empty();
+        for value in to_remove {
+            self.remove_from_domain(ti, &value);
+        }
+        revised
+    }
+
+    /// AC-3: prunes `domains` to arc consistency, starting from the arcs
+    /// pointing into `changed` (the teams whose domains were just touched)
+    /// rather than rebuilding the full O(n²) queue from scratch. Called with
+    /// every active team once up front to establish initial consistency,
+    /// and with just the two teams an `assign` booked on every subsequent
+    /// call from `solve` — per the original design, a revised domain
+    /// re-enqueues the arcs `(Tk, Ti)` for every other active neighbor `Tk`.
+    /// Returns false as soon as a domain is wiped out.
+    fn ac3(&mut self, changed: &[Team]) -> bool {
+        let active: Vec<Team> = self
+            .teams
+            .iter()
+            .filter(|team| self.needs_more_matches(team))
+            .cloned()
+            .collect();
+
+        let mut queue: VecDeque<(Team, Team)> = VecDeque::new();
+        for ti in changed {
+            if !self.needs_more_matches(ti) {
+                continue;
+            }
+            for tk in &active {
+                if tk != ti {
+                    queue.push_back((tk.clone(), ti.clone()));
+                }
+            }
+        }
+
+        while let Some((ti, tj)) = queue.pop_front() {
+            if !self.needs_more_matches(&ti) || !self.needs_more_matches(&tj) {
+                continue;
+            }
+            if self.revise(&ti, &tj) {
+                if self.domains.get(&ti).is_none_or(HashSet::is_empty) {
+                    return false;
+                }
+                for tk in &active {
+                    if tk != &ti && tk != &tj && self.needs_more_matches(tk) {
+                        queue.push_back((tk.clone(), ti.clone()));
+                    }
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Recursive backtracking search: pick the next team by MRV, try each
+    /// remaining domain candidate cheapest-soft-cost first, propagate with
+    /// AC-3, and unwind via the trail whenever a branch dead-ends. Ordering
+    /// by cost biases the first complete schedule found towards a low
+    /// total soft-constraint cost, without the expense of enumerating
+    /// every feasible schedule to find the true optimum.
+    ///
+    /// `team` may still need a match against `opponent` with either side at
+    /// home — e.g. once `team`'s home slot for `opponent`'s pot is full but
+    /// its away slot isn't. So each opponent contributes a candidate for
+    /// every orientation (`team` home, or `opponent` home) that the hard
+    /// constraints actually allow, rather than always booking `team` home;
+    /// otherwise a team left needing only away fixtures could never be
+    /// assigned once selected, and the search would dead-end forever.
+    fn solve(&mut self) -> bool {
+        let team = match self.select_unassigned_team() {
+            Some(team) => team,
+            None => return true,
+        };
+
+        let opponents: Vec<Team> = self
+            .domains
+            .get(&team)
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .collect();
+
+        let mut candidates: Vec<(Team, bool)> = Vec::new();
+        for opponent in &opponents {
+            if !self.needs_more_matches(opponent) {
+                continue;
+            }
+            if self.satisfies_constraints(&team, opponent) {
+                candidates.push((opponent.clone(), true));
+            }
+            if self.satisfies_constraints(opponent, &team) {
+                candidates.push((opponent.clone(), false));
+            }
+        }
+        candidates.sort_by_key(|(opponent, team_home)| {
+            if *team_home {
+                self.soft_cost(&team, opponent)
+            } else {
+                self.soft_cost(opponent, &team)
+            }
+        });
+
+        for (opponent, team_home) in candidates {
+            if !self.domains.get(&team).is_some_and(|domain| domain.contains(&opponent)) {
+                // Defensive: every earlier candidate is undone back to `mark`,
+                // so the domain here is the one this call started with.
+                continue;
+            }
+
+            let mark = self.trail.len();
+            let assigned = if team_home {
+                self.assign(&team, &opponent)
             } else {
-                entry.0 += 1; 
+                self.assign(&opponent, &team)
+            };
+            if !assigned {
+                continue;
             }
+
+            if self.ac3(&[team.clone(), opponent]) && self.solve() {
+                return true;
+            }
+
+            self.undo_to(mark);
         }
+
+        false
     }
 
-    fn schedule_matches(&mut self) {
-        let team_list: Vec<_> = self.teams.clone();
-        
-        for team in &team_list {
-            let domain_list: Vec<_> = self.domains.get(team).unwrap().clone().into_iter().collect();
-            
-            for opponent in domain_list {
-                if self.satisfies_constraints(team, &opponent) {
-                    let new_match = Match::new(team.clone(), opponent.clone());
-                    self.scheduled_matches.push(new_match.clone());
-                    self.update_group_tracking(team, &opponent, true);
+    fn schedule_matches(&mut self) -> bool {
+        let all_teams = self.teams.clone();
+        if !self.ac3(&all_teams) {
+            return false;
+        }
+        self.solve()
+    }
 
-                    let return_match = Match::new(opponent.clone(), team.clone());
-                    self.scheduled_matches.push(return_match);
-                    self.update_group_tracking(&opponent, team, false);
-                    self.domains.get_mut(team).unwrap().remove(&opponent);
-                    self.domains.get_mut(&opponent).unwrap().remove(team);
+    /// Partitions `scheduled_matches` into matchdays: a greedy edge-colouring
+    /// of the "plays" graph, where each match gets the lowest round number
+    /// not already used by either of its two teams.
+    fn assign_rounds(&mut self) {
+        let mut used_rounds: HashMap<Team, HashSet<u8>> = HashMap::new();
 
+        for m in &mut self.scheduled_matches {
+            let mut round: u8 = 1;
+            loop {
+                let home_used = used_rounds
+                    .get(&m.home_team)
+                    .is_some_and(|rounds| rounds.contains(&round));
+                let away_used = used_rounds
+                    .get(&m.away_team)
+                    .is_some_and(|rounds| rounds.contains(&round));
+                if !home_used && !away_used {
                     break;
                 }
+                round += 1;
             }
+
+            m.round = round;
+            used_rounds.entry(m.home_team.clone()).or_default().insert(round);
+            used_rounds.entry(m.away_team.clone()).or_default().insert(round);
         }
     }
 
-    fn display_matches(&self) {
-        for m in &self.scheduled_matches {
-            println!(
-                "Match: {} (Home) vs {} (Away)",
-                m.home_team.name, m.away_team.name
-            );
+    /// Stamps every match with a kickoff of `start + (round - 1) * spacing`.
+    /// Call after `assign_rounds`, which is what gives `round` its meaning.
+    fn assign_kickoffs(&mut self, start: DateTime<Utc>, spacing: Duration) {
+        for m in &mut self.scheduled_matches {
+            m.kickoff = Some(start + spacing * i32::from(m.round - 1));
         }
     }
 
-    fn save_matches(&self) {
-        let mut matches = self.scheduled_matches.clone();
-        matches.sort_by(|a, b| a.home_team.name.cmp(&b.home_team.name));
-        let mut wtr = csv::Writer::from_path("src/data/Scheduled_Matches.csv").unwrap();
-        wtr.write_record(&["Home Team", "Away Team"]).unwrap();
-        for m in matches {
-            wtr.write_record(&[&m.home_team.name, &m.away_team.name]).unwrap();
+    fn display_matches(&self) {
+        for m in &self.scheduled_matches {
+            match m.kickoff {
+                Some(kickoff) => println!(
+                    "Matchday {}: {} (Home) vs {} (Away) — {}",
+                    m.round,
+                    m.home_team.name,
+                    m.away_team.name,
+                    kickoff.to_rfc3339()
+                ),
+                None => println!(
+                    "Matchday {}: {} (Home) vs {} (Away)",
+                    m.round, m.home_team.name, m.away_team.name
+                ),
+            }
         }
-        wtr.flush().unwrap();
     }
 }
 
 fn main() {
-    let teams = read_teams("src/data/Teams_Data.csv");
-    let mut csp = CSPMatches::new(teams);
+    let config = match config::load_config(Path::new("src/data/config.toml")) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("failed to load src/data/config.toml: {e}");
+            return;
+        }
+    };
+
+    let teams = match config::load_teams(Path::new("src/data/teams")) {
+        Ok(teams) => teams,
+        Err(e) => {
+            eprintln!("failed to load src/data/teams: {e}");
+            return;
+        }
+    };
+
+    if let Err(errors) = config::validate(&teams, &config) {
+        for e in errors {
+            eprintln!("{e}");
+        }
+        return;
+    }
 
-    csp.schedule_matches();
-    csp.display_matches();
-    csp.save_matches();
+    let mut csp = CSPMatches::new(teams, &config);
+
+    if csp.schedule_matches() {
+        csp.assign_rounds();
+        csp.assign_kickoffs(Utc::now(), Duration::weeks(1));
+        csp.display_matches();
+        csp.save("src/data/Scheduled_Matches.csv", Format::Csv).unwrap();
+        csp.save("src/data/Scheduled_Matches.json", Format::Json).unwrap();
+        csp.save("src/data/Scheduled_Matches.yaml", Format::Yaml).unwrap();
+        csp.export_team_dumps("src/data/team_fixtures").unwrap();
+    } else {
+        eprintln!("No valid schedule satisfies the constraints.");
+    }
 }
 
-fn read_teams(file_path: &str) -> Vec<Team> {
-    let mut teams = Vec::new();
-    let file = std::fs::File::open(file_path).unwrap();
-    let mut rdr = csv::Reader::from_reader(file);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn team(name: &str, country: &str, group: u8) -> Team {
+        Team {
+            name: name.to_string(),
+            country: country.to_string(),
+            group,
+        }
+    }
 
-    for result in rdr.records() {
-        let record = result.unwrap();
-        if record.get(0).map(|s| s == "team").unwrap_or(false) {
-            continue;
+    fn config(pots: u8, matches_per_team: u8, country_cap: u8) -> TournamentConfig {
+        TournamentConfig {
+            pots,
+            matches_per_team,
+            country_cap,
         }
+    }
 
-        let team_name = record.get(0).unwrap_or("").to_string();
-        let country = record.get(1).unwrap_or("").to_string();
-        let group: u8 = record.get(2).unwrap_or("0").parse().unwrap();
+    #[test]
+    fn solves_a_small_feasible_instance() {
+        let teams = vec![
+            team("A", "Avalon", 1),
+            team("B", "Borealis", 1),
+            team("C", "Cantor", 1),
+            team("D", "Delmira", 1),
+        ];
+        let cfg = config(1, 2, teams.len() as u8);
+        let mut csp = CSPMatches::new(teams.clone(), &cfg);
 
-        let team = Team::new(team_name, country, group);
-        teams.push(team);
+        assert!(csp.schedule_matches());
+        assert_eq!(csp.scheduled_matches.len(), teams.len());
+        for team in &teams {
+            assert_eq!(csp.matches_played(team), 2);
+            assert!(csp.is_pot_balanced(team));
+        }
     }
 
-    teams
+    #[test]
+    fn rejects_an_infeasible_instance() {
+        // Each team needs 2 distinct pot-1 opponents (one home, one away),
+        // but with only one other team in the pool there's nobody left to
+        // supply the second leg without repeating an opponent.
+        let teams = vec![team("A", "Avalon", 1), team("B", "Borealis", 1)];
+        let cfg = config(1, 2, teams.len() as u8);
+        let mut csp = CSPMatches::new(teams, &cfg);
+
+        assert!(!csp.schedule_matches());
+    }
 }